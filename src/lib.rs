@@ -45,35 +45,87 @@ macro_rules! chain {
     // Thus for example it transforms 5 + 4 < 10 <= 20 * 2 into
     // (5 + 4) < (10) <= (20 * 2)
 
-    // @wrap uses two square brackets containing tokens to save its current
-    // state as it processes tokens. The first contains everything that has
-    // been parsed so far, and the second contains the tokens that have
-    // appeared since the previous comparison operator. This means that when
-    // a new comparison operator is encountered, the tokens in the second
-    // bracket can be wrapped in parentheses and added to the first bracket.
+    // After its mode tag (described below), @wrap uses three square brackets
+    // containing tokens to save its current state as it processes tokens. The
+    // first of these tracks the current angle bracket
+    // nesting depth (one `@` per open generic), the second contains everything
+    // that has been parsed so far, and the third contains the tokens that have
+    // appeared since the previous comparison operator. This means that when a
+    // new comparison operator is encountered, the tokens in the third bracket
+    // can be wrapped in parentheses and added to the second bracket.
+
+    // The depth counter exists so that the `<`/`>` inside turbofish and generic
+    // type parameters (e.g. `foo::<T>()`) are not mistaken for chain comparison
+    // operators: a comparator is only recognized when the depth is zero.
 
     // For example to call it for 5 + 4 < 10 + 5< 20 you would do
-    // chain!(@wrap [] [5] + 4 < 10 + 5 < 20)
+    // chain!(@wrap [chain] [] [] [5] + 4 < 10 + 5 < 20)
     // and part way through parsing the calls could be
-    // chain!(@wrap [(5 + 4)] [10 +] 5 < 20)
-    
-    (@wrap [$($prev:tt)*] [$($cur:tt)+] <  $next:tt $($rest:tt)*) => {
-        chain!(@wrap [$($prev)* ($($cur)*) <] [$next] $($rest)*)
+    // chain!(@wrap [chain] [] [(5 + 4) <] [10 +] 5 < 20)
+
+    // The first argument to @wrap is a mode tag carried unchanged through the
+    // whole parse: `[chain]` when expanding to a boolean for `chain!`, and
+    // `[assert [<chain string>] [<format args>]]` when expanding to an
+    // assertion for `assert_chain!`. Only the terminal rule cares which mode is
+    // in effect; every other rule just threads it along.
+
+    // A comparison operator is only treated as a chain comparator when the
+    // angle bracket depth (second bracket) is zero
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] <  $next:tt $($rest:tt)*) => {
+        chain!(@wrap $mode [] [$($prev)* ($($cur)*) <] [$next] $($rest)*)
     };
-    (@wrap [$($prev:tt)*] [$($cur:tt)+] <= $next:tt $($rest:tt)*) => {
-        chain!(@wrap [$($prev)* ($($cur)*) <=] [$next] $($rest)*)
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] <= $next:tt $($rest:tt)*) => {
+        chain!(@wrap $mode [] [$($prev)* ($($cur)*) <=] [$next] $($rest)*)
     };
-    (@wrap [$($prev:tt)*] [$($cur:tt)+] >  $next:tt $($rest:tt)*) => {
-        chain!(@wrap [$($prev)* ($($cur)*) >] [$next] $($rest)*)
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] >  $next:tt $($rest:tt)*) => {
+        chain!(@wrap $mode [] [$($prev)* ($($cur)*) >] [$next] $($rest)*)
     };
-    (@wrap [$($prev:tt)*] [$($cur:tt)+] >= $next:tt $($rest:tt)*) => {
-        chain!(@wrap [$($prev)* ($($cur)*) >=] [$next] $($rest)*)
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] >= $next:tt $($rest:tt)*) => {
+        chain!(@wrap $mode [] [$($prev)* ($($cur)*) >=] [$next] $($rest)*)
     };
-    (@wrap [$($prev:tt)*] [$($cur:tt)+] == $next:tt $($rest:tt)*) => {
-        chain!(@wrap [$($prev)* ($($cur)*) ==] [$next] $($rest)*)
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] == $next:tt $($rest:tt)*) => {
+        chain!(@wrap $mode [] [$($prev)* ($($cur)*) ==] [$next] $($rest)*)
     };
-    (@wrap [$($prev:tt)*] [$($cur:tt)+] != $next:tt $($rest:tt)*) => {
-        chain!(@wrap [$($prev)* ($($cur)*) !=] [$next] $($rest)*)
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] != $next:tt $($rest:tt)*) => {
+        chain!(@wrap $mode [] [$($prev)* ($($cur)*) !=] [$next] $($rest)*)
+    };
+
+    // A boolean operator cannot be part of a chain: the chain only links
+    // comparisons, so suggest splitting it in two around the `&&`/`||`, echoing
+    // the operands on either side of the offending operator
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] && $($rest:tt)*) => {
+        compile_error!(concat!(
+            "`&&` cannot appear inside a chained comparison; split it into two, ",
+            "e.g. `chain!(… ", stringify!($($cur)*), ") && chain!(",
+            stringify!($($rest)*), ")`"
+        ));
+    };
+    (@wrap $mode:tt [] [$($prev:tt)*] [$($cur:tt)+] || $($rest:tt)*) => {
+        compile_error!(concat!(
+            "`||` cannot appear inside a chained comparison; split it into two, ",
+            "e.g. `chain!(… ", stringify!($($cur)*), ") || chain!(",
+            stringify!($($rest)*), ")`"
+        ));
+    };
+
+    // A turbofish (`::<`) opens a generic argument list; increment the depth
+    // counter and keep accumulating its tokens into the current group
+    (@wrap $mode:tt [$($d:tt)*] [$($prev:tt)*] [$($cur:tt)+] :: < $($rest:tt)*) => {
+        chain!(@wrap $mode [@ $($d)*] [$($prev)*] [$($cur)* :: <] $($rest)*)
+    };
+    // A bare `<` while already inside a generic opens a nested one
+    (@wrap $mode:tt [@ $($d:tt)*] [$($prev:tt)*] [$($cur:tt)+] < $($rest:tt)*) => {
+        chain!(@wrap $mode [@ @ $($d)*] [$($prev)*] [$($cur)* <] $($rest)*)
+    };
+    // A `>>` closes two generic levels at once; consume it, push a single `>`
+    // into the buffer and re-feed a synthetic `>` so the second level is closed
+    // by the rule below (the technique anyhow's `ensure!` uses)
+    (@wrap $mode:tt [@ $($d:tt)*] [$($prev:tt)*] [$($cur:tt)+] >> $($rest:tt)*) => {
+        chain!(@wrap $mode [$($d)*] [$($prev)*] [$($cur)* >] > $($rest)*)
+    };
+    // A `>` closes the innermost generic level
+    (@wrap $mode:tt [@ $($d:tt)*] [$($prev:tt)*] [$($cur:tt)+] > $($rest:tt)*) => {
+        chain!(@wrap $mode [$($d)*] [$($prev)*] [$($cur)* >] $($rest)*)
     };
 
     (@arg_err $op:tt) => {
@@ -83,21 +135,36 @@ macro_rules! chain {
     };
     // Match errors where a comparison operator is left trailing at the end of
     // the input, and call error function
-    (@wrap [$($a:tt)*] [$($b:tt)*] < ) => { chain!(@arg_err <)  };
-    (@wrap [$($a:tt)*] [$($b:tt)*] <=) => { chain!(@arg_err <=) };
-    (@wrap [$($a:tt)*] [$($b:tt)*] > ) => { chain!(@arg_err >)  };
-    (@wrap [$($a:tt)*] [$($b:tt)*] >=) => { chain!(@arg_err >=) };
-    (@wrap [$($a:tt)*] [$($b:tt)*] ==) => { chain!(@arg_err ==) };
-    (@wrap [$($a:tt)*] [$($b:tt)*] !=) => { chain!(@arg_err !=) };
+    (@wrap $m:tt [$($d:tt)*] [$($a:tt)*] [$($b:tt)*] < ) => { chain!(@arg_err <)  };
+    (@wrap $m:tt [$($d:tt)*] [$($a:tt)*] [$($b:tt)*] <=) => { chain!(@arg_err <=) };
+    (@wrap $m:tt [$($d:tt)*] [$($a:tt)*] [$($b:tt)*] > ) => { chain!(@arg_err >)  };
+    (@wrap $m:tt [$($d:tt)*] [$($a:tt)*] [$($b:tt)*] >=) => { chain!(@arg_err >=) };
+    (@wrap $m:tt [$($d:tt)*] [$($a:tt)*] [$($b:tt)*] ==) => { chain!(@arg_err ==) };
+    (@wrap $m:tt [$($d:tt)*] [$($a:tt)*] [$($b:tt)*] !=) => { chain!(@arg_err !=) };
+
+    // Reached the end without ever seeing a comparison operator (the parsed
+    // bracket is still empty): the invocation is a single bare expression
+    // rather than a chain
+    (@wrap $mode:tt [$($d:tt)*] [] [$($cur:tt)+]) => {
+        compile_error!(concat!(
+            "`", stringify!($($cur)*), "` is not a chained comparison; at least ",
+            "one comparison operator (`<`, `<=`, `>`, `>=`, `==`, `!=`) is required"
+        ));
+    };
 
-    // Matches when all the tokens have been parsed. Then calls @op on the
-    // wrapped tokens
-    (@wrap [$($prev:tt)*] [$($cur:tt)+]) => { chain!(@op $($prev)* ($($cur)*)) };
+    // Matches when all the tokens have been parsed. In chain mode call @op to
+    // evaluate the boolean; in assert mode hand off to @aop
+    (@wrap [chain] [$($d:tt)*] [$($prev:tt)*] [$($cur:tt)+]) => {
+        chain!(@op $($prev)* ($($cur)*))
+    };
+    (@wrap [assert [$($chain:tt)*] [$($fmt:tt)*]] [$($d:tt)*] [$($prev:tt)*] [$($cur:tt)+]) => {
+        chain!(@aop [$($chain)*] [$($fmt)*] $($prev)* ($($cur)*))
+    };
 
     // Matches when the next token to parse isnt a comparison operator, and just
     // adds this next token to the current capture group
-    (@wrap [$($prev:tt)*] [$($cur:tt)+] $next:tt $($rest:tt)*) => {
-        chain!(@wrap [$($prev)*] [$($cur)* $next] $($rest)*)
+    (@wrap $mode:tt [$($d:tt)*] [$($prev:tt)*] [$($cur:tt)+] $next:tt $($rest:tt)*) => {
+        chain!(@wrap $mode [$($d)*] [$($prev)*] [$($cur)* $next] $($rest)*)
     };
 
     // @op acts like a function that recursively expands chained comparison
@@ -119,7 +186,36 @@ macro_rules! chain {
     (@op $($rest:tt)*) => {{
         compile_error!("Expected comparison operator (<, <=, >, >=, ==, !=)");
     }};
-    
+
+    // @aop mirrors @op for assert_chain!. It evaluates each sub-comparison in
+    // turn using the same left-to-right single-evaluation guarantee (the second
+    // operand of each comparison is reused as the first of the next), and on
+    // failure panics with a message naming the failing sub-comparison and the
+    // runtime operand values. `[$chain]` is the stringified whole chain and
+    // `[$($fmt)*]` the user's optional trailing format arguments.
+    // Operands arrive already parenthesized by @wrap; peel that layer off so
+    // the reported operand strings match the source (`x`, not `(x)`) while the
+    // parentheses still guard evaluation precedence in the `let` bindings.
+    (@aop [$($chain:tt)*] [$($fmt:tt)*] ($($a:tt)*) $op:tt ($($b:tt)*) $($rest:tt)*) => {{
+        let first = ($($a)*);
+        chain!(@aop_cont [$($chain)*] [$($fmt)*] first, stringify!($($a)*), $op ($($b)*) $($rest)*)
+    }};
+    (@aop_cont [$($chain:tt)*] [$($fmt:tt)*] $lhs:expr, $lstr:expr, $op:tt ($($b:tt)*)) => {{
+        let left = $lhs;
+        let right = ($($b)*);
+        $crate::__assert_chain_check!(
+            left, right, $op, $($chain)*, $lstr, stringify!($op), stringify!($($b)*), [$($fmt)*]
+        );
+    }};
+    (@aop_cont [$($chain:tt)*] [$($fmt:tt)*] $lhs:expr, $lstr:expr, $op:tt ($($b:tt)*) $($rest:tt)+) => {{
+        let left = $lhs;
+        let right = ($($b)*);
+        $crate::__assert_chain_check!(
+            left, right, $op, $($chain)*, $lstr, stringify!($op), stringify!($($b)*), [$($fmt)*]
+        );
+        chain!(@aop_cont [$($chain)*] [$($fmt)*] right, stringify!($($b)*), $($rest)*)
+    }};
+
     // Throw errors if there is no left hand argument to the first comparison
     (<  $($rest:tt)*) => { chain!(@arg_err <)  };
     (<= $($rest:tt)*) => { chain!(@arg_err <=) };
@@ -128,9 +224,279 @@ macro_rules! chain {
     (== $($rest:tt)*) => { chain!(@arg_err ==) };
     (!= $($rest:tt)*) => { chain!(@arg_err !=) };
 
+    // An empty invocation has nothing to compare
+    () => {
+        compile_error!(
+            "chain! requires at least one comparison, e.g. `chain!(a < b)`"
+        );
+    };
+
     // Entrypoint
     ($first:tt $($rest:tt)*) => {
-        chain!(@wrap [] [$first] $($rest)*)
+        chain!(@wrap [chain] [] [] [$first] $($rest)*)
+    };
+}
+
+/// Validate that `$op` is one of the comparison operators understood by this
+/// crate, emitting a `compile_error!` naming the allowed operators otherwise.
+///
+/// This is an implementation detail shared by [`all_of!`] and [`any_of!`] and
+/// is not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cmpchain_check_op {
+    (<)  => {};
+    (<=) => {};
+    (>)  => {};
+    (>=) => {};
+    (==) => {};
+    (!=) => {};
+    ($other:tt) => {
+        compile_error!(concat!(
+            "invalid comparison operator `", stringify!($other),
+            "`, expected one of `<`, `<=`, `>`, `>=`, `==`, `!=`"
+        ));
+    };
+}
+
+/// Evaluate a single sub-comparison for [`assert_chain!`], panicking with a
+/// descriptive message when it fails.
+///
+/// The two arms select whether the user supplied a trailing format-args payload
+/// (like the message accepted by [`assert!`]). This is an implementation detail
+/// and is not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_chain_check {
+    ($left:expr, $right:expr, $op:tt, $chain:expr, $lstr:expr, $opstr:expr, $rstr:expr, []) => {
+        if !($left $op $right) {
+            panic!(
+                "assertion failed: {} {} {} (left = {:?}, right = {:?}) in chain {}",
+                $lstr, $opstr, $rstr, $left, $right, $chain
+            );
+        }
+    };
+    ($left:expr, $right:expr, $op:tt, $chain:expr, $lstr:expr, $opstr:expr, $rstr:expr, [$($fmt:tt)+]) => {
+        if !($left $op $right) {
+            panic!(
+                "assertion failed: {} {} {} (left = {:?}, right = {:?}) in chain {}: {}",
+                $lstr, $opstr, $rstr, $left, $right, $chain, format_args!($($fmt)+)
+            );
+        }
+    };
+}
+
+/// Check that every operand satisfies the same comparison against a shared
+/// right hand side.
+///
+/// `all_of!(a, b, c; < limit)` expands to the conjunction
+/// `a < limit && b < limit && c < limit`. Unlike [`chain!`], which links each
+/// operand to its neighbour, `all_of!` compares every operand against the same
+/// threshold, covering the common "is each value within a bound" pattern. The
+/// right hand side is evaluated exactly once, bound to a temporary before the
+/// conjunction, and the operands are tested left to right so that evaluation
+/// short circuits on the first one that fails. The same operators as [`chain!`]
+/// are supported: `<`, `<=`, `>`, `>=`, `==`, `!=`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate cmpchain;
+/// let limit = 10;
+/// assert!(all_of!(1, 4, 9; < limit));
+/// assert!(!all_of!(1, 4, 20; < limit));
+/// ```
+#[macro_export]
+macro_rules! all_of {
+    ($($operand:expr),+ ; $op:tt $rhs:expr) => {{
+        $crate::__cmpchain_check_op!($op);
+        let rhs = $rhs;
+        $($operand $op rhs)&&+
+    }};
+}
+
+/// Check that at least one operand satisfies the same comparison against a
+/// shared right hand side.
+///
+/// `any_of!(a, b, c; >= limit)` expands to the disjunction
+/// `a >= limit || b >= limit || c >= limit`. It is the counterpart to
+/// [`all_of!`]: the right hand side is evaluated exactly once, bound to a
+/// temporary before the disjunction, and the operands are tested left to right
+/// so that evaluation short circuits on the first one that succeeds. The same
+/// operators as [`chain!`] are supported: `<`, `<=`, `>`, `>=`, `==`, `!=`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate cmpchain;
+/// let limit = 10;
+/// assert!(any_of!(20, 4, 30; < limit));
+/// assert!(!any_of!(11, 12, 13; < limit));
+/// ```
+#[macro_export]
+macro_rules! any_of {
+    ($($operand:expr),+ ; $op:tt $rhs:expr) => {{
+        $crate::__cmpchain_check_op!($op);
+        let rhs = $rhs;
+        $($operand $op rhs)||+
+    }};
+}
+
+/// Compare every unordered pair of operands with a single repeated relation.
+///
+/// Unlike [`chain!`], which only links neighbouring operands, `pairwise!`
+/// checks every pair, so `pairwise!(a != b != c)` guarantees that `a`, `b` and
+/// `c` are *all* distinct (including `a != c`), and `pairwise!(a == b == c)`
+/// that they are all equal. The operands must be joined by a single repeated
+/// operator, either `!=` (all distinct) or `==` (all equal); mixing operators
+/// is a compile error, since pairwise semantics only make sense for a single
+/// relation.
+///
+/// Each operand is evaluated exactly once, left to right, before any
+/// comparisons are made, and evaluation short circuits on the first pair that
+/// fails the relation. The operands must share a type that implements
+/// [`PartialEq`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate cmpchain;
+/// assert!(pairwise!(1 != 2 != 3));
+/// // `chain!` would be satisfied here, but 1 and 1 are not distinct
+/// assert!(!pairwise!(1 != 2 != 1));
+/// assert!(pairwise!(4 == 2 * 2 == 8 / 2));
+/// ```
+#[macro_export]
+macro_rules! pairwise {
+    // @munch walks the operands like chain!'s @wrap, wrapping each in
+    // parentheses and accumulating them in the second bracket. The first
+    // bracket records the relation in force (`?` until the first operator is
+    // seen), so that a differing operator later can be rejected.
+
+    // The first operator establishes the relation, seeding the next operand's
+    // buffer with the following token so the muncher has something to grow
+    (@munch [?] [$($ops:tt)*] [$($cur:tt)+] != $next:tt $($rest:tt)*) => {
+        $crate::pairwise!(@munch [!=] [$($ops)* ($($cur)*),] [$next] $($rest)*)
+    };
+    (@munch [?] [$($ops:tt)*] [$($cur:tt)+] == $next:tt $($rest:tt)*) => {
+        $crate::pairwise!(@munch [==] [$($ops)* ($($cur)*),] [$next] $($rest)*)
+    };
+    // Subsequent operators must match the established relation
+    (@munch [!=] [$($ops:tt)*] [$($cur:tt)+] != $next:tt $($rest:tt)*) => {
+        $crate::pairwise!(@munch [!=] [$($ops)* ($($cur)*),] [$next] $($rest)*)
+    };
+    (@munch [==] [$($ops:tt)*] [$($cur:tt)+] == $next:tt $($rest:tt)*) => {
+        $crate::pairwise!(@munch [==] [$($ops)* ($($cur)*),] [$next] $($rest)*)
+    };
+    // A differing operator is rejected: pairwise semantics need one relation
+    (@munch [!=] [$($ops:tt)*] [$($cur:tt)+] == $($rest:tt)*) => {
+        compile_error!(
+            "pairwise! requires a single relation; found both `!=` and `==`"
+        );
+    };
+    (@munch [==] [$($ops:tt)*] [$($cur:tt)+] != $($rest:tt)*) => {
+        compile_error!(
+            "pairwise! requires a single relation; found both `==` and `!=`"
+        );
+    };
+    // End of input: emit the array of operands and test every unordered pair
+    (@munch [!=] [$($ops:tt)*] [$($cur:tt)+]) => {{
+        let operands = [$($ops)* ($($cur)*)];
+        operands
+            .iter()
+            .enumerate()
+            .all(|(i, a)| operands.iter().skip(i + 1).all(|b| a != b))
+    }};
+    (@munch [==] [$($ops:tt)*] [$($cur:tt)+]) => {{
+        let operands = [$($ops)* ($($cur)*)];
+        operands
+            .iter()
+            .enumerate()
+            .all(|(i, a)| operands.iter().skip(i + 1).all(|b| a == b))
+    }};
+    // Reached the end without ever seeing a relation: a single operand
+    (@munch [?] [$($ops:tt)*] [$($cur:tt)+]) => {
+        compile_error!(concat!(
+            "pairwise! requires at least two operands joined by `!=` (all \
+             distinct) or `==` (all equal); found only `",
+            stringify!($($cur)*), "`"
+        ));
+    };
+    // Any other token belongs to the current operand
+    (@munch [$op:tt] [$($ops:tt)*] [$($cur:tt)+] $next:tt $($rest:tt)*) => {
+        $crate::pairwise!(@munch [$op] [$($ops)*] [$($cur)* $next] $($rest)*)
+    };
+
+    () => {
+        compile_error!(
+            "pairwise! requires at least two operands, e.g. `pairwise!(a != b)`"
+        );
+    };
+    ($first:tt $($rest:tt)*) => {
+        $crate::pairwise!(@munch [?] [] [$first] $($rest)*)
+    };
+}
+
+/// Assert that a chained comparison holds, reporting the offending
+/// sub-comparison and its operand values on failure.
+///
+/// `assert_chain!` accepts the same chained-comparison syntax as [`chain!`] and
+/// shares its left-to-right single-evaluation guarantee, so the values reported
+/// on failure are exactly the ones that were evaluated. When a sub-comparison
+/// fails it panics with a message naming that comparison and the runtime
+/// operands, e.g. `assert_chain!(4 < x <= 10)` with `x == 11` panics with
+/// `assertion failed: x <= 10 (left = 11, right = 10) in chain 4 < x <= 10`.
+/// As with [`assert!`], an optional trailing format-args payload may be
+/// supplied to extend the message. The operands must implement
+/// [`Debug`](core::fmt::Debug) so their values can be rendered.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate cmpchain;
+/// let x = 8;
+/// assert_chain!(4 < x <= 10);
+/// assert_chain!(4 < x <= 10, "x was {}", x);
+/// ```
+///
+/// ```should_panic
+/// # #[macro_use] extern crate cmpchain;
+/// let x = 11;
+/// assert_chain!(4 < x <= 10);
+/// ```
+#[macro_export]
+macro_rules! assert_chain {
+    // Split the invocation into the chain and an optional trailing format-args
+    // payload at the first top-level comma. Commas inside parenthesized,
+    // bracketed or braced operands are hidden within their own token trees; a
+    // comma between turbofish generic arguments (`foo::<A, B>()`) is not, and
+    // is taken as the separator.
+    (@split [$($chain:tt)*] , $($fmt:tt)+) => {
+        $crate::assert_chain!(@start [$($chain)*] [$($fmt)+])
+    };
+    (@split [$($chain:tt)*]) => {
+        $crate::assert_chain!(@start [$($chain)*] [])
+    };
+    (@split [$($chain:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_chain!(@split [$($chain)* $next] $($rest)*)
+    };
+
+    // Seed chain!'s @wrap tokenizer in assert mode, capturing the stringified
+    // chain for the failure message.
+    (@start [$first:tt $($rest:tt)*] [$($fmt:tt)*]) => {
+        $crate::chain!(
+            @wrap [assert [stringify!($first $($rest)*)] [$($fmt)*]] [] [] [$first] $($rest)*
+        )
+    };
+
+    () => {
+        compile_error!(
+            "assert_chain! requires at least one comparison, e.g. `assert_chain!(a < b)`"
+        );
+    };
+
+    ($($input:tt)+) => {
+        $crate::assert_chain!(@split [] $($input)+)
     };
 }
 
@@ -182,6 +548,107 @@ mod tests {
         assert!(chain!(4 < 4 * 2 <= 4 * 3));
     }
 
+    #[test]
+    fn pairwise_distinct_and_equal() {
+        // Every unordered pair is checked, not just neighbours
+        assert!(pairwise!(1 != 2 != 3));
+        assert!(!pairwise!(1 != 2 != 1));
+        assert!(pairwise!(4 == 2 * 2 == 8 / 2));
+        assert!(!pairwise!(1 == 1 == 2));
+    }
+
+    #[test]
+    fn pairwise_single_evaluation() {
+        // Each operand is evaluated exactly once, left to right
+        let mut results: Vec<i32> = Vec::new();
+        let mut side_effect = |val: i32| {
+            results.push(val);
+            val
+        };
+        assert!(pairwise!(side_effect(1) != side_effect(2) != side_effect(3)));
+        assert_eq!(results, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn turbofish_and_generics() {
+        // The `<` and `>` inside turbofish and generic type parameters must not
+        // be treated as chain comparators
+        assert!(chain!(1 < std::cmp::max::<i32>(2, 3) > 0));
+        assert!(chain!(2 == "ab".to_string().parse::<String>().unwrap().len() == 2));
+        // Doubled closing brackets (`>>`) closing two nested generics
+        assert!(chain!(0 <= core::iter::empty::<Vec<i32>>().count() <= 0));
+    }
+
+    #[test]
+    fn set_comparisons() {
+        // all_of! is the conjunction, any_of! the disjunction, of the same
+        // comparison applied to every operand against a shared threshold
+        let limit = 10;
+        assert!(all_of!(1, 4, 9; < limit));
+        assert!(!all_of!(1, 4, 20; < limit));
+        assert!(all_of!(5, 5, 5; == 5));
+
+        assert!(any_of!(20, 4, 30; < limit));
+        assert!(!any_of!(11, 12, 13; < limit));
+    }
+
+    #[test]
+    fn set_comparison_side_effects() {
+        // The shared right hand side is evaluated exactly once, and operands
+        // are evaluated left to right, short circuiting on the first decisive
+        // comparison
+        let mut results: Vec<i32> = Vec::new();
+        let mut side_effect = |val: i32| {
+            results.push(val);
+            val
+        };
+        assert!(all_of!(side_effect(1), side_effect(2); < 10));
+        assert_eq!(results, &[1, 2]);
+
+        let mut results: Vec<i32> = Vec::new();
+        let mut side_effect = |val: i32| {
+            results.push(val);
+            val
+        };
+        assert!(any_of!(side_effect(1), side_effect(2), side_effect(3); < 2));
+        assert_eq!(results, &[1]);
+    }
+
+    #[test]
+    fn assert_chain_passes() {
+        let x = 8;
+        assert_chain!(4 < x <= 10);
+        assert_chain!(1 == 1 == 1);
+        // Optional trailing format args are accepted like assert!
+        assert_chain!(4 < x <= 10, "x = {}", x);
+    }
+
+    #[test]
+    #[should_panic(expected = "in chain 4 < x <= 10")]
+    fn assert_chain_reports_failure() {
+        let x = 11;
+        assert_chain!(4 < x <= 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "custom message 11")]
+    fn assert_chain_custom_message() {
+        let x = 11;
+        assert_chain!(4 < x <= 10, "custom message {}", x);
+    }
+
+    #[test]
+    fn assert_chain_single_evaluation() {
+        // Operands are evaluated exactly once, left to right
+        let mut results: Vec<i32> = Vec::new();
+        let mut side_effect = |val: i32| {
+            results.push(val);
+            val
+        };
+        assert_chain!(side_effect(1) < side_effect(2) <= side_effect(3));
+        assert_eq!(results, &[1, 2, 3]);
+    }
+
     #[test]
     fn compile_fail_tests() {
         let t = trybuild::TestCases::new();
@@ -0,0 +1,8 @@
+use cmpchain::all_of;
+
+fn main() {
+    // Only the crate's comparison operators are accepted; anything else must
+    // be rejected by the shared operator-validation rule.
+    let x = true;
+    let _ = all_of!(true, false; & x);
+}
@@ -0,0 +1,7 @@
+use cmpchain::chain;
+
+fn main() {
+    // A boolean operator cannot live inside a chain; the error should suggest
+    // splitting it into two separate comparisons.
+    let _ = chain!(a < b && c);
+}
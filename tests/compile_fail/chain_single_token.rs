@@ -0,0 +1,6 @@
+use cmpchain::chain;
+
+fn main() {
+    // A lone expression with no comparison operator is not a chain.
+    let _ = chain!(x);
+}
@@ -0,0 +1,7 @@
+use cmpchain::pairwise;
+
+fn main() {
+    // Pairwise semantics only make sense for a single relation, so mixing
+    // `!=` and `==` in one invocation must be rejected.
+    let _ = pairwise!(1 != 2 == 3);
+}